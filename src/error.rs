@@ -0,0 +1,49 @@
+//! Error types for fallible [`NTPTimestamp`](crate::NTPTimestamp) conversions.
+
+/// Error returned when a byte slice is too short to decode an
+/// [`NTPTimestamp`](crate::NTPTimestamp).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromBytesError {
+    pub(crate) expected: usize,
+    pub(crate) actual: usize,
+}
+
+impl core::fmt::Display for TryFromBytesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected at least {} bytes, got {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for TryFromBytesError {}
+
+/// Errors produced by the fallible [`NTPTimestamp`](crate::NTPTimestamp)
+/// constructors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NtpTimestampError {
+    /// The system clock is set to a time before the UNIX epoch.
+    PreEpoch,
+    /// The computed NTP seconds value overflows a `u32`, i.e. the input is
+    /// past the year-2036 NTP rollover.
+    SecondsOverflow,
+}
+
+impl core::fmt::Display for NtpTimestampError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PreEpoch => write!(f, "system time is earlier than the UNIX epoch"),
+            Self::SecondsOverflow => {
+                write!(f, "seconds value overflows a u32 (past the 2036 NTP rollover)")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl std::error::Error for NtpTimestampError {}
@@ -0,0 +1,166 @@
+//! Optional leap-second-aware UTC/TAI conversions for
+//! [`NTPTimestamp`](crate::NTPTimestamp).
+//!
+//! NTP timestamps nominally count UTC seconds and therefore stall or repeat
+//! across a leap second. Applications that need a continuous scale (TAI)
+//! can supply a [`LeapSecondTable`] and use the methods below; the default
+//! conversions on [`NTPTimestamp`](crate::NTPTimestamp) are unaffected and
+//! require no table.
+
+#[cfg(not(feature = "std"))]
+use core::time;
+
+#[cfg(feature = "std")]
+use std::time;
+
+use crate::{NTPTimestamp, NTP_EPOCH_DELTA};
+
+/// A table of leap seconds, supplied by the caller.
+///
+/// Each entry is `(ntp_seconds, offset)`, where `offset` is the cumulative
+/// TAI-UTC offset, in seconds, that applies from `ntp_seconds` onward.
+/// Entries must be sorted by ascending `ntp_seconds`.
+#[derive(Debug, Clone, Copy)]
+pub struct LeapSecondTable<'a> {
+    entries: &'a [(u64, i8)],
+}
+
+impl<'a> LeapSecondTable<'a> {
+    /// Creates a [`LeapSecondTable`] from a list of `(ntp_seconds, offset)`
+    /// entries, sorted by ascending `ntp_seconds`.
+    #[must_use]
+    pub fn new(entries: &'a [(u64, i8)]) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the cumulative TAI-UTC offset, in seconds, that applies at
+    /// the given NTP second count.
+    #[must_use]
+    pub fn offset_at(&self, ntp_seconds: u64) -> i8 {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(at, _)| *at <= ntp_seconds)
+            .map_or(0, |(_, offset)| *offset)
+    }
+}
+
+impl NTPTimestamp {
+    /// Converts this UTC [`NTPTimestamp`] to a continuous TAI
+    /// [`time::Duration`] since the NTP epoch, applying `table`'s cumulative
+    /// leap-second offset.
+    #[must_use]
+    pub fn to_tai_duration(&self, table: &LeapSecondTable<'_>) -> time::Duration {
+        let offset = table.offset_at(u64::from(self.seconds()));
+        let tai_seconds = i64::from(self.seconds()) + i64::from(offset);
+        let tai_seconds = u64::try_from(tai_seconds).unwrap_or(0);
+
+        time::Duration::from_secs(tai_seconds) + time::Duration::from_nanos(self.fraction_as_ns())
+    }
+
+    /// Converts a continuous TAI [`time::Duration`] since the NTP epoch back
+    /// to a UTC [`NTPTimestamp`], removing `table`'s cumulative leap-second
+    /// offset.
+    ///
+    /// `table` is keyed by UTC/NTP seconds, but only the TAI instant is
+    /// known up front, so the offset is resolved by guessing a UTC second
+    /// from the TAI-indexed offset and refining the guess against the UTC
+    /// axis until it stabilizes; this converges in at most a couple of
+    /// iterations since leap-second offsets only ever change by one second
+    /// at a time. The refinement stops after two rounds: a TAI second that
+    /// falls inside an inserted leap second has no exact UTC counterpart and
+    /// resolves to the UTC second that follows the leap.
+    pub fn from_tai_duration(duration: &time::Duration, table: &LeapSecondTable<'_>) -> Self {
+        let tai_seconds = duration.as_secs();
+        let mut offset = table.offset_at(tai_seconds);
+        let mut rounds = 0;
+
+        let utc_seconds = loop {
+            let utc_seconds = u64::try_from(
+                i64::try_from(tai_seconds).unwrap_or(i64::MAX) - i64::from(offset),
+            )
+            .unwrap_or(0);
+            let refined = table.offset_at(utc_seconds);
+            rounds += 1;
+
+            if refined == offset || rounds == 2 {
+                break utc_seconds;
+            }
+
+            offset = refined;
+        };
+
+        let fraction = Self::micros_fraction(duration.subsec_micros());
+
+        Self::new(u32::try_from(utc_seconds).unwrap_or(u32::MAX), fraction)
+    }
+
+    /// Converts this [`NTPTimestamp`] to a Unix timestamp, applying
+    /// `table`'s cumulative leap-second offset so that UTC seconds
+    /// stalled/repeated around a leap second are corrected for.
+    #[must_use]
+    pub fn to_unix_timestamp_leap_aware(&self, table: &LeapSecondTable<'_>) -> u64 {
+        let offset = table.offset_at(u64::from(self.seconds()));
+        let corrected = i64::from(self.seconds()) + i64::from(offset);
+        let corrected = u64::try_from(corrected).unwrap_or(0);
+
+        corrected.saturating_sub(NTP_EPOCH_DELTA.as_secs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEAP_TABLE: &[(u64, i8)] = &[(3_692_217_600, 1), (3_723_753_600, 2)];
+
+    #[test]
+    fn test_offset_at_before_first_entry() {
+        let table = LeapSecondTable::new(LEAP_TABLE);
+
+        assert_eq!(table.offset_at(0), 0);
+    }
+
+    #[test]
+    fn test_offset_at_applies_cumulative_offset() {
+        let table = LeapSecondTable::new(LEAP_TABLE);
+
+        assert_eq!(table.offset_at(3_692_217_600), 1);
+        assert_eq!(table.offset_at(3_723_753_601), 2);
+    }
+
+    #[test]
+    fn test_tai_duration_roundtrip() {
+        let table = LeapSecondTable::new(LEAP_TABLE);
+        let t = NTPTimestamp::new(3_723_753_700, 0);
+
+        let tai = t.to_tai_duration(&table);
+        let back = NTPTimestamp::from_tai_duration(&tai, &table);
+
+        assert_eq!(back.seconds(), t.seconds());
+    }
+
+    #[test]
+    fn test_tai_duration_roundtrip_inside_post_leap_window() {
+        // With entries at 1000 (+10) and 2000 (+11), utc=1995 maps to
+        // tai=2005, which is >= the 2000 boundary: a naive `offset_at(tai)`
+        // lookup on the way back would pick up the +11 offset instead of
+        // the +10 that actually applies at utc=1995.
+        let table = LeapSecondTable::new(&[(1000, 10), (2000, 11)]);
+        let t = NTPTimestamp::new(1995, 0);
+
+        let tai = t.to_tai_duration(&table);
+        assert_eq!(tai.as_secs(), 2005);
+
+        let back = NTPTimestamp::from_tai_duration(&tai, &table);
+        assert_eq!(back.seconds(), t.seconds());
+    }
+
+    #[test]
+    fn test_to_unix_timestamp_leap_aware() {
+        let table = LeapSecondTable::new(&[]);
+        let t = NTPTimestamp::new(3_849_984_000, 0);
+
+        assert_eq!(t.to_unix_timestamp_leap_aware(&table), 1_640_995_200);
+    }
+}
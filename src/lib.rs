@@ -13,6 +13,21 @@ use std::time;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "std")]
+mod hlc;
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+pub use hlc::{Hlc, HlcBuilder, HlcError};
+
+mod error;
+
+pub use error::{NtpTimestampError, TryFromBytesError};
+
+mod leap;
+
+pub use leap::LeapSecondTable;
+
 /// This is the number of seconds between the NTP epoch *1st January 1900* and
 /// the Unix epoch *1st January 1970*.
 pub const NTP_EPOCH_DELTA: time::Duration = time::Duration::from_secs(2_208_988_800);
@@ -119,6 +134,78 @@ impl NTPTimestamp {
         seconds + fraction
     }
 
+    /// Returns the big-endian byte representation of the full 64-bit NTP
+    /// timestamp, as used for the `transmit`/`receive`/`origin`/`reference`
+    /// timestamp fields of an NTP packet.
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 8] {
+        self.timestamp().to_be_bytes()
+    }
+
+    /// Creates an [`NTPTimestamp`] from its full 64-bit big-endian byte
+    /// representation.
+    pub fn from_be_bytes(bytes: &[u8; 8]) -> Self {
+        Self::from_ntp_timestamp(u64::from_be_bytes(*bytes))
+    }
+
+    /// Creates an [`NTPTimestamp`] from a big-endian byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryFromBytesError`] if `bytes` is shorter than 8 bytes.
+    pub fn try_from_be_bytes(bytes: &[u8]) -> Result<Self, TryFromBytesError> {
+        bytes
+            .get(..8)
+            .and_then(|s| <[u8; 8]>::try_from(s).ok())
+            .map(|b| Self::from_be_bytes(&b))
+            .ok_or(TryFromBytesError {
+                expected: 8,
+                actual: bytes.len(),
+            })
+    }
+
+    /// Returns the NTP Short Format (16 bits seconds, 16 bits fraction) as
+    /// big-endian bytes, as used for the root delay/dispersion fields of an
+    /// NTP packet.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn to_short_be_bytes(&self) -> [u8; 4] {
+        let seconds = self.seconds as u16;
+        let fraction = (self.fraction >> 16) as u16;
+
+        let mut bytes = [0u8; 4];
+        bytes[..2].copy_from_slice(&seconds.to_be_bytes());
+        bytes[2..].copy_from_slice(&fraction.to_be_bytes());
+
+        bytes
+    }
+
+    /// Creates an [`NTPTimestamp`] from its NTP Short Format big-endian byte
+    /// representation.
+    pub fn from_short_be_bytes(bytes: &[u8; 4]) -> Self {
+        let seconds = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let fraction = u16::from_be_bytes([bytes[2], bytes[3]]);
+
+        Self::new(u32::from(seconds), u32::from(fraction) << 16)
+    }
+
+    /// Creates an [`NTPTimestamp`] from a NTP Short Format big-endian byte
+    /// slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryFromBytesError`] if `bytes` is shorter than 4 bytes.
+    pub fn try_from_short_be_bytes(bytes: &[u8]) -> Result<Self, TryFromBytesError> {
+        bytes
+            .get(..4)
+            .and_then(|s| <[u8; 4]>::try_from(s).ok())
+            .map(|b| Self::from_short_be_bytes(&b))
+            .ok_or(TryFromBytesError {
+                expected: 4,
+                actual: bytes.len(),
+            })
+    }
+
     /// Converts [`time::Duration`] to [`NTPTimestamp`].
     /// Expects a `Duration` since Unix epoch.
     pub fn from_unix_duration(duration: &time::Duration) -> Self {
@@ -218,8 +305,14 @@ impl NTPTimestamp {
         (ts + NTP_EPOCH_DELTA.as_secs()) as u32
     }
 
+    fn try_from_unix_sec(ts: u64) -> Result<u32, NtpTimestampError> {
+        let seconds = ts + NTP_EPOCH_DELTA.as_secs();
+
+        u32::try_from(seconds).map_err(|_| NtpTimestampError::SecondsOverflow)
+    }
+
     #[allow(clippy::cast_possible_truncation)]
-    fn micros_fraction(ts: u32) -> u32 {
+    pub(crate) fn micros_fraction(ts: u32) -> u32 {
         let ts = u64::from(ts);
         let us = u64::from(SEC_AS_US);
         let scale = u64::from(u32::MAX);
@@ -227,6 +320,12 @@ impl NTPTimestamp {
         ((ts * scale) / us) as u32
     }
 
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[allow(clippy::cast_possible_truncation)]
+    fn nanos_fraction(ns: u32) -> u32 {
+        ((u64::from(ns) << 32) / SEC_AS_NS) as u32
+    }
+
     fn decode_from_u64(ts: u64) -> Self {
         let seconds = ((ts & SECONDS_BITMASK) >> 32) as u32;
         let fraction = (ts & FRACTION_BITMASK) as u32;
@@ -237,6 +336,72 @@ impl NTPTimestamp {
     fn encode_to_u64(high: u32, low: u32) -> u64 {
         (u64::from(high) << 32) | u64::from(low)
     }
+
+    /// Converts a [`time::Duration`] into a 64-bit fixed-point NTP value
+    /// (32 bits of seconds, 32 bits of fraction) suitable for adding to or
+    /// subtracting from [`Self::timestamp`].
+    pub(crate) fn duration_to_ntp64(duration: &time::Duration) -> u64 {
+        let seconds = duration.as_secs() << 32;
+        let fraction = (u64::from(duration.subsec_nanos()) << 32) / SEC_AS_NS;
+
+        seconds | fraction
+    }
+}
+
+/// Shifts the timestamp forward by a [`time::Duration`].
+///
+/// The addition is performed on the 64-bit fixed-point representation and
+/// wraps on overflow, so a timestamp shifted past the 2036 seconds rollover
+/// silently wraps around to a small `seconds` value rather than panicking.
+impl core::ops::Add<time::Duration> for NTPTimestamp {
+    type Output = Self;
+
+    fn add(self, rhs: time::Duration) -> Self::Output {
+        let delta = Self::duration_to_ntp64(&rhs);
+
+        Self::from_ntp_timestamp(self.timestamp().wrapping_add(delta))
+    }
+}
+
+/// Shifts the timestamp forward by a [`time::Duration`] in place.
+///
+/// See the [`Add`](#impl-Add<Duration>-for-NTPTimestamp) impl for the
+/// wrapping behaviour around the 2036 rollover.
+impl core::ops::AddAssign<time::Duration> for NTPTimestamp {
+    fn add_assign(&mut self, rhs: time::Duration) {
+        *self = *self + rhs;
+    }
+}
+
+/// Shifts the timestamp backward by a [`time::Duration`].
+///
+/// The subtraction is performed on the 64-bit fixed-point representation and
+/// wraps on underflow, so subtracting past `seconds == 0` wraps around to a
+/// timestamp near the 2036 rollover rather than panicking.
+impl core::ops::Sub<time::Duration> for NTPTimestamp {
+    type Output = Self;
+
+    fn sub(self, rhs: time::Duration) -> Self::Output {
+        let delta = Self::duration_to_ntp64(&rhs);
+
+        Self::from_ntp_timestamp(self.timestamp().wrapping_sub(delta))
+    }
+}
+
+/// Returns the gap between two timestamps as a [`time::Duration`].
+///
+/// The difference is computed as an unsigned 64-bit wrapping subtraction of
+/// the fixed-point representations and decoded back through
+/// [`Self::fraction_as_ns`], so `rhs` is expected to be earlier than `self`;
+/// passing a later `rhs` wraps around rather than yielding a negative value.
+impl core::ops::Sub<NTPTimestamp> for NTPTimestamp {
+    type Output = time::Duration;
+
+    fn sub(self, rhs: NTPTimestamp) -> Self::Output {
+        let delta = self.timestamp().wrapping_sub(rhs.timestamp());
+
+        Self::from_ntp_timestamp(delta).to_duration()
+    }
 }
 
 #[cfg(feature = "std")]
@@ -245,7 +410,9 @@ impl NTPTimestamp {
     ///
     /// # Panics
     ///
-    /// This function panics if the system time is earlier than the UNIX epoch.
+    /// This function panics if the system time is earlier than the UNIX
+    /// epoch or past the year-2036 NTP rollover. See [`Self::try_now`] for a
+    /// non-panicking alternative.
     ///
     /// # Examples
     /// ```
@@ -256,14 +423,34 @@ impl NTPTimestamp {
     #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
     #[cfg(feature = "std")]
     pub fn now() -> Self {
+        Self::try_now().expect("failed to read the current system time as an NTPTimestamp")
+    }
+
+    /// Returns the current system time as an [`NTPTimestamp`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NtpTimestampError::PreEpoch`] if the system time is earlier
+    /// than the UNIX epoch, or [`NtpTimestampError::SecondsOverflow`] if it
+    /// is past the year-2036 NTP rollover.
+    ///
+    /// # Examples
+    /// ```
+    /// use ntp_timestamp::NTPTimestamp;
+    ///
+    /// let now = NTPTimestamp::try_now().unwrap();
+    /// ```
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    pub fn try_now() -> Result<Self, NtpTimestampError> {
         let ts = time::SystemTime::now()
             .duration_since(time::UNIX_EPOCH)
-            .expect("System time is earlier than UNIX epoch");
+            .map_err(|_| NtpTimestampError::PreEpoch)?;
 
-        let seconds = Self::from_unix_sec(ts.as_secs());
+        let seconds = Self::try_from_unix_sec(ts.as_secs())?;
         let fraction = Self::micros_fraction(ts.subsec_micros());
 
-        Self::new(seconds, fraction)
+        Ok(Self::new(seconds, fraction))
     }
 
     /// Returns the NTP epoch as a [`std::time::SystemTime`].
@@ -335,6 +522,92 @@ impl<'de> Deserialize<'de> for NTPTimestamp {
     }
 }
 
+/// Feature-gated conversions to/from [`chrono::DateTime<chrono::Utc>`].
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+impl NTPTimestamp {
+    /// Converts a UTC [`chrono::DateTime`] to an [`NTPTimestamp`], preserving
+    /// sub-second precision.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn from_chrono_datetime(dt: &chrono::DateTime<chrono::Utc>) -> Self {
+        let seconds = Self::from_unix_sec(dt.timestamp() as u64);
+        let fraction = Self::nanos_fraction(dt.timestamp_subsec_nanos());
+
+        Self::new(seconds, fraction)
+    }
+
+    /// Converts the [`NTPTimestamp`] to a UTC [`chrono::DateTime`],
+    /// preserving sub-second precision.
+    #[allow(clippy::cast_possible_wrap)]
+    #[must_use]
+    pub fn to_chrono_datetime(&self) -> chrono::DateTime<chrono::Utc> {
+        let secs = i64::from(self.seconds) - NTP_EPOCH_DELTA.as_secs() as i64;
+        let nanos = u32::try_from(self.fraction_as_ns()).unwrap_or(u32::MAX);
+
+        chrono::DateTime::from_timestamp(secs, nanos).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+impl From<NTPTimestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(ts: NTPTimestamp) -> Self {
+        ts.to_chrono_datetime()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+impl From<chrono::DateTime<chrono::Utc>> for NTPTimestamp {
+    fn from(dt: chrono::DateTime<chrono::Utc>) -> Self {
+        Self::from_chrono_datetime(&dt)
+    }
+}
+
+/// Feature-gated conversions to/from [`time::OffsetDateTime`](::time::OffsetDateTime).
+#[cfg(feature = "time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
+impl NTPTimestamp {
+    /// Converts a [`::time::OffsetDateTime`] to an [`NTPTimestamp`],
+    /// preserving sub-second precision.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn from_offset_datetime(dt: &::time::OffsetDateTime) -> Self {
+        let seconds = Self::from_unix_sec(dt.unix_timestamp() as u64);
+        let fraction = Self::nanos_fraction(dt.nanosecond());
+
+        Self::new(seconds, fraction)
+    }
+
+    /// Converts the [`NTPTimestamp`] to a [`::time::OffsetDateTime`],
+    /// preserving sub-second precision.
+    #[allow(clippy::cast_possible_wrap)]
+    #[must_use]
+    pub fn to_offset_datetime(&self) -> ::time::OffsetDateTime {
+        let secs = i64::from(self.seconds) - NTP_EPOCH_DELTA.as_secs() as i64;
+        let nanos = i64::try_from(self.fraction_as_ns()).unwrap_or(0);
+
+        ::time::OffsetDateTime::from_unix_timestamp(secs)
+            .unwrap_or(::time::OffsetDateTime::UNIX_EPOCH)
+            + ::time::Duration::nanoseconds(nanos)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
+impl From<NTPTimestamp> for ::time::OffsetDateTime {
+    fn from(ts: NTPTimestamp) -> Self {
+        ts.to_offset_datetime()
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
+impl From<::time::OffsetDateTime> for NTPTimestamp {
+    fn from(dt: ::time::OffsetDateTime) -> Self {
+        Self::from_offset_datetime(&dt)
+    }
+}
+
 /// Extends [`core::time::Duration`] with methods to convert to [`NTPTimestamp`].
 #[cfg(not(feature = "std"))]
 impl DurationExt for time::Duration {
@@ -473,4 +746,119 @@ mod tests {
         assert_eq!(t.fraction_as_ns(), 125_000_000);
         assert_eq!(t.fraction_as_ps(), 125_000_000_000);
     }
+
+    #[test]
+    fn test_add_duration() {
+        let t = NTPTimestamp::new(1_000, 0);
+        let d = time::Duration::from_secs(500);
+
+        assert_eq!((t + d).seconds(), 1_500);
+    }
+
+    #[test]
+    fn test_add_assign_duration() {
+        let mut t = NTPTimestamp::new(1_000, 0);
+        t += time::Duration::from_secs(500);
+
+        assert_eq!(t.seconds(), 1_500);
+    }
+
+    #[test]
+    fn test_sub_duration() {
+        let t = NTPTimestamp::new(1_000, 0);
+        let d = time::Duration::from_secs(500);
+
+        assert_eq!((t - d).seconds(), 500);
+    }
+
+    #[test]
+    fn test_sub_timestamp() {
+        let a = NTPTimestamp::new(1_000, 0);
+        let b = NTPTimestamp::new(700, 0);
+
+        assert_eq!((a - b).as_secs(), 300);
+    }
+
+    #[test]
+    fn test_add_duration_wraps_on_rollover() {
+        let t = NTPTimestamp::new(u32::MAX, 0);
+        let d = time::Duration::from_secs(1);
+
+        assert_eq!((t + d).seconds(), 0);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_datetime_roundtrip() {
+        let dt = chrono::DateTime::from_timestamp(1_640_995_200, 250_000_000).unwrap();
+        let t = NTPTimestamp::from_chrono_datetime(&dt);
+
+        assert_eq!(t.to_unix_timestamp(), 1_640_995_200);
+        assert_eq!(t.to_chrono_datetime(), dt);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_offset_datetime_roundtrip() {
+        let dt = ::time::OffsetDateTime::from_unix_timestamp(1_640_995_200).unwrap();
+        let t = NTPTimestamp::from_offset_datetime(&dt);
+
+        assert_eq!(t.seconds(), 3_849_984_000);
+        assert_eq!(t.to_offset_datetime(), dt);
+    }
+
+    #[test]
+    fn test_be_bytes_roundtrip() {
+        let t = NTPTimestamp::new(1_000_000, 0x4000_0000);
+        let bytes = t.to_be_bytes();
+
+        assert_eq!(NTPTimestamp::from_be_bytes(&bytes), t);
+        assert_eq!(NTPTimestamp::try_from_be_bytes(&bytes).unwrap(), t);
+    }
+
+    #[test]
+    fn test_try_from_be_bytes_too_short() {
+        let err = NTPTimestamp::try_from_be_bytes(&[0u8; 4]).unwrap_err();
+
+        assert_eq!(err, TryFromBytesError {
+            expected: 8,
+            actual: 4,
+        });
+    }
+
+    #[test]
+    fn test_short_be_bytes_roundtrip() {
+        let t = NTPTimestamp::new(1_000_000, 0x4000_0000);
+        let bytes = t.to_short_be_bytes();
+
+        let round = NTPTimestamp::from_short_be_bytes(&bytes);
+        assert_eq!(round.seconds(), u32::from(1_000_000u32 as u16));
+        assert_eq!(round.fraction(), 0x4000_0000);
+        assert_eq!(NTPTimestamp::try_from_short_be_bytes(&bytes).unwrap(), round);
+    }
+
+    #[test]
+    fn test_try_from_short_be_bytes_too_short() {
+        let err = NTPTimestamp::try_from_short_be_bytes(&[0u8; 2]).unwrap_err();
+
+        assert_eq!(err, TryFromBytesError {
+            expected: 4,
+            actual: 2,
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_try_now() {
+        let now = NTPTimestamp::try_now().unwrap();
+
+        assert!(now.seconds() > 0);
+    }
+
+    #[test]
+    fn test_try_from_unix_sec_overflow() {
+        let err = NTPTimestamp::try_from_unix_sec(u64::from(u32::MAX)).unwrap_err();
+
+        assert_eq!(err, NtpTimestampError::SecondsOverflow);
+    }
 }